@@ -6,6 +6,24 @@ enum MidiState {
     ExpectOneRunningByte(u8),
     ExpectTwoRunningBytes(u8),
     ExpectSysExData(usize),
+    ExpectSysExFragment(usize),
+}
+
+/// How the parser behaves when a System Exclusive payload grows past the `N`-byte buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SysExMode {
+    /// Hold the whole message in the buffer; report [`StreamError::BufferOverflow`] if it does not
+    /// fit. This is the default and preserves the original single-buffer behavior.
+    SingleBuffer,
+    /// Emit the buffered bytes as a partial fragment whenever the buffer fills and keep consuming,
+    /// delivering a final fragment on the `0xf7` stop byte. The leading `0xf0` appears only on the
+    /// first fragment and the trailing `0xf7` only on the last.
+    ///
+    /// Fragments are raw byte slices, not whole messages, so the decoding APIs
+    /// ([`push_message`](StreamParser::push_message), [`iter_read`](StreamParser::iter_read),
+    /// [`stream`](StreamParser::stream)) cannot be used in this mode — drive the parser with
+    /// [`push`](StreamParser::push) / [`try_push`](StreamParser::try_push) and reassemble yourself.
+    Fragments,
 }
 
 enum ByteType {
@@ -21,6 +39,180 @@ enum ByteType {
 pub struct StreamParser<'a, const N: usize> {
     message_buffer: &'a mut [u8; N],
     state: MidiState,
+    sysex_mode: SysExMode,
+    last_partial: bool,
+    flush_pending: bool,
+}
+
+/// Error reported by [`StreamParser::try_push`] when the byte stream cannot be parsed into the
+/// fixed-size buffer. The parser resets cleanly to its initial state after returning any of these,
+/// so the caller can decide whether to resync or grow the buffer.
+///
+/// The backlog item also listed an `UnexpectedStatus` variant, but a status byte interrupting an
+/// in-progress message is valid MIDI — it simply aborts the current message and starts a new one
+/// (see the status arms of the state machine behind [`StreamParser::try_push`]). Reporting it as an error would both
+/// diverge from that well-defined behavior and desync the stream on the [`push`](StreamParser::push)
+/// path (which maps errors to `None`), so the variant is intentionally omitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamError {
+    /// A SysEx payload grew past the `N`-byte buffer; the message was truncated.
+    BufferOverflow,
+    /// A data byte (`0x00`–`0x7f`) arrived with no status to attach it to.
+    UnexpectedDataByte,
+}
+
+/// Error produced while decoding a MIDI byte stream pulled from an I/O source.
+#[cfg(any(feature = "std", feature = "async"))]
+#[derive(Debug)]
+pub enum ParseError {
+    /// The underlying reader returned an error.
+    Io(std::io::Error),
+    /// A completed message could not be converted into a [`MidiMessage`](crate::MidiMessage).
+    Parse(crate::FromBytesError),
+}
+
+#[cfg(any(feature = "std", feature = "async"))]
+impl From<std::io::Error> for ParseError {
+    fn from(err: std::io::Error) -> Self {
+        ParseError::Io(err)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "async"))]
+impl From<crate::FromBytesError> for ParseError {
+    fn from(err: crate::FromBytesError) -> Self {
+        ParseError::Parse(err)
+    }
+}
+
+/// Blocking adapter that drives a [`StreamParser`] over a [`std::io::Read`] source, decoding one
+/// [`MidiMessage`](crate::MidiMessage) per message boundary. Running-status and in-progress SysEx
+/// state are carried across read boundaries; [`next`](Self::next) returns `None` at EOF even if a
+/// message is left mid-assembly.
+///
+/// Each decoded message borrows the parser's buffer, so this is a *lending* iterator and cannot
+/// implement [`Iterator`]; drive it with a `while let` loop:
+///
+/// ```ignore
+/// let mut messages = StreamParser::new(&mut buffer).iter_read(file);
+/// while let Some(result) = messages.next() {
+///     let message = result?;
+///     // ...
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub struct ReadMessages<'a, R: std::io::Read, const N: usize> {
+    parser: StreamParser<'a, N>,
+    reader: R,
+    chunk: [u8; 64],
+    filled: usize,
+    pos: usize,
+    done: bool,
+}
+
+#[cfg(feature = "std")]
+impl<'a, R: std::io::Read, const N: usize> ReadMessages<'a, R, N> {
+    /// Pull bytes from the reader until the next message boundary and decode it, or return `None`
+    /// at EOF. The returned message borrows `self` until the following call.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Result<crate::MidiMessage<'_>, ParseError>> {
+        // Advance the FSM to the next message boundary without holding a borrow of `self.parser`
+        // across loop iterations, then take the single buffer borrow after the loop.
+        let range = loop {
+            if self.pos == self.filled {
+                if self.done {
+                    return None;
+                }
+                match self.reader.read(&mut self.chunk) {
+                    Ok(0) => {
+                        self.done = true;
+                        return None;
+                    }
+                    Ok(n) => {
+                        self.filled = n;
+                        self.pos = 0;
+                    }
+                    Err(err) => {
+                        self.done = true;
+                        return Some(Err(ParseError::Io(err)));
+                    }
+                }
+            }
+            let byte = self.chunk[self.pos];
+            self.pos += 1;
+            if let Some(range) = self.parser.next_message_range(byte) {
+                break range;
+            }
+        };
+        Some(crate::MidiMessage::try_from(&self.parser.message_buffer[range]).map_err(ParseError::Parse))
+    }
+}
+
+/// Asynchronous adapter that drives a [`StreamParser`] over an
+/// [`AsyncRead`](futures::io::AsyncRead) source, decoding one [`MidiMessage`](crate::MidiMessage)
+/// per message boundary. Running-status and in-progress SysEx state are preserved between
+/// [`poll_next`](Self::poll_next) calls, and real-time bytes injected mid-message are surfaced
+/// without disturbing the reconstruction. Polling returns `None` at EOF.
+///
+/// Each decoded message borrows the parser's buffer, so (like [`ReadMessages`]) this is a *lending*
+/// source and cannot implement [`futures::Stream`]; poll it directly from an async context or a
+/// hand-written [`core::future::Future`].
+#[cfg(feature = "async")]
+pub struct MessageStream<'a, R: futures::io::AsyncRead + Unpin, const N: usize> {
+    parser: StreamParser<'a, N>,
+    reader: R,
+    chunk: [u8; 64],
+    filled: usize,
+    pos: usize,
+    done: bool,
+}
+
+#[cfg(feature = "async")]
+impl<'a, R: futures::io::AsyncRead + Unpin, const N: usize> MessageStream<'a, R, N> {
+    /// Poll the underlying source for the next decoded message. Returns [`Poll::Pending`] while the
+    /// reader has no bytes ready, `Poll::Ready(None)` at EOF, and `Poll::Ready(Some(..))` on a
+    /// message boundary. The returned message borrows `self` until the following call.
+    pub fn poll_next(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Option<Result<crate::MidiMessage<'_>, ParseError>>> {
+        use core::task::Poll;
+        use futures::io::AsyncRead;
+
+        let this = self.get_mut();
+        // As in `ReadMessages::next`, advance to a boundary returning a borrow-free range so the
+        // single buffer borrow is taken once, after the loop.
+        let range = loop {
+            if this.pos == this.filled {
+                if this.done {
+                    return Poll::Ready(None);
+                }
+                match core::pin::Pin::new(&mut this.reader).poll_read(cx, &mut this.chunk) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok(0)) => {
+                        this.done = true;
+                        return Poll::Ready(None);
+                    }
+                    Poll::Ready(Ok(n)) => {
+                        this.filled = n;
+                        this.pos = 0;
+                    }
+                    Poll::Ready(Err(err)) => {
+                        this.done = true;
+                        return Poll::Ready(Some(Err(ParseError::Io(err))));
+                    }
+                }
+            }
+            let byte = this.chunk[this.pos];
+            this.pos += 1;
+            if let Some(range) = this.parser.next_message_range(byte) {
+                break range;
+            }
+        };
+        Poll::Ready(Some(
+            crate::MidiMessage::try_from(&this.parser.message_buffer[range]).map_err(ParseError::Parse),
+        ))
+    }
 }
 
 // State machine loosely based on https://cdn.sparkfun.com/assets/learn_tutorials/4/0/8/midi-fsm3.png
@@ -32,74 +224,236 @@ impl<'a, const N: usize> StreamParser<'a, N> {
         StreamParser {
             message_buffer,
             state: MidiState::ExpectAnyByte,
+            sysex_mode: SysExMode::SingleBuffer,
+            last_partial: false,
+            flush_pending: false,
         }
     }
+
+    /// Select how oversized SysEx payloads are handled. Defaults to [`SysExMode::SingleBuffer`] for
+    /// backward compatibility; pass [`SysExMode::Fragments`] to stream arbitrarily large dumps
+    /// through the fixed buffer as a sequence of fragments.
+    pub fn with_sysex_mode(mut self, mode: SysExMode) -> Self {
+        self.sysex_mode = mode;
+        self
+    }
+
+    /// Whether the last call to [`push`](Self::push) / [`try_push`](Self::try_push) returned a
+    /// non-final SysEx fragment with more bytes still to come (only possible under
+    /// [`SysExMode::Fragments`]). The next byte pushed clears the flag, and the final fragment,
+    /// carrying the `0xf7` stop byte, returns `false`.
+    pub fn sysex_fragment_pending(&self) -> bool {
+        self.last_partial
+    }
     pub fn push(&mut self, byte: u8) -> Option<&[u8]> {
+        self.try_push(byte).ok().flatten()
+    }
+
+    /// Like [`push`](Self::push), but surfaces malformed input as a [`StreamError`] instead of
+    /// silently discarding it. A SysEx longer than the `N`-byte buffer reports
+    /// [`StreamError::BufferOverflow`] and resets rather than indexing out of bounds. On any error
+    /// the parser returns to [`MidiState::ExpectAnyByte`] so the next valid status resyncs the
+    /// stream.
+    pub fn try_push(&mut self, byte: u8) -> Result<Option<&[u8]>, StreamError> {
+        let range = self.advance(byte)?;
+        Ok(range.map(move |r| &self.message_buffer[r]))
+    }
+
+    /// Advance the state machine by one byte, writing any completed message into `message_buffer`
+    /// and returning the index range that holds it (instead of a borrow of the buffer). Keeping the
+    /// return value borrow-free lets callers loop over incoming bytes and only take the buffer
+    /// borrow once, after the loop — the shape a lending iterator needs to compile on stable Rust.
+    fn advance(&mut self, byte: u8) -> Result<Option<core::ops::Range<usize>>, StreamError> {
+        self.last_partial = false;
+        // A fragment was flushed on the previous byte; start the next one with an empty buffer so
+        // the incoming byte lands at index 0 (no leading 0xf0 on continuation fragments).
+        if self.flush_pending {
+            self.flush_pending = false;
+            self.state = MidiState::ExpectSysExFragment(0);
+        }
         let byte = Self::get_byte_type(byte);
         match (&self.state, byte) {
             (MidiState::ExpectFirstDataByte(s), ByteType::DataByte(b)) => {
                 self.state = MidiState::ExpectSecondDataByte(*s, b);
-                return None;
+                Ok(None)
             }
             (MidiState::ExpectSecondDataByte(s, b1), ByteType::DataByte(b2)) => {
                 self.message_buffer[0] = *s;
                 self.message_buffer[1] = *b1;
                 self.message_buffer[2] = b2;
                 self.state = MidiState::ExpectTwoRunningBytes(*s);
-                return Some(&self.message_buffer[0..3]);
+                Ok(Some(0..3))
             }
             (MidiState::ExpectOneDataByte(s), ByteType::DataByte(b)) => {
                 self.message_buffer[0] = *s;
                 self.message_buffer[1] = b;
                 self.state = MidiState::ExpectOneRunningByte(*s);
-                return Some(&self.message_buffer[0..2]);
+                Ok(Some(0..2))
             }
             (MidiState::ExpectOneRunningByte(s), ByteType::DataByte(b)) => {
                 self.message_buffer[0] = *s;
                 self.message_buffer[1] = b;
-                return Some(&self.message_buffer[0..2]);
+                Ok(Some(0..2))
             }
             (MidiState::ExpectTwoRunningBytes(s), ByteType::DataByte(b)) => {
                 self.state = MidiState::ExpectSecondDataByte(*s, b);
-                return None;
+                Ok(None)
             }
             (MidiState::ExpectSysExData(n), ByteType::DataByte(b)) => {
-                let n = *n + 1;
-                self.message_buffer[n] = b;
-                self.state = MidiState::ExpectSysExData(n);
-                return None;
+                let i = *n + 1;
+                if i >= N {
+                    // The buffer is already full and cannot take another byte.
+                    self.state = MidiState::ExpectAnyByte;
+                    return Err(StreamError::BufferOverflow);
+                }
+                self.message_buffer[i] = b;
+                if i == N - 1 && self.sysex_mode == SysExMode::Fragments {
+                    // Buffer just filled: flush what we have as a partial fragment (with the
+                    // leading 0xf0) and continue on the next byte.
+                    self.last_partial = true;
+                    self.flush_pending = true;
+                    return Ok(Some(0..N));
+                }
+                self.state = MidiState::ExpectSysExData(i);
+                Ok(None)
             }
             (MidiState::ExpectSysExData(n), ByteType::SysExStopByte(b)) => {
-                let n = *n + 1;
-                self.message_buffer[n] = b;
+                let i = *n + 1;
+                if i >= N {
+                    self.state = MidiState::ExpectAnyByte;
+                    return Err(StreamError::BufferOverflow);
+                }
+                self.message_buffer[i] = b;
                 self.state = MidiState::ExpectAnyByte;
-                return Some(&self.message_buffer[0..n+1]);
+                Ok(Some(0..i + 1))
+            }
+            (MidiState::ExpectSysExFragment(c), ByteType::DataByte(b)) => {
+                let c = *c;
+                self.message_buffer[c] = b;
+                if c + 1 == N {
+                    // Continuation fragment filled: flush it and resume on the next byte.
+                    self.last_partial = true;
+                    self.flush_pending = true;
+                    return Ok(Some(0..N));
+                }
+                self.state = MidiState::ExpectSysExFragment(c + 1);
+                Ok(None)
             }
+            (MidiState::ExpectSysExFragment(c), ByteType::SysExStopByte(b)) => {
+                let c = *c;
+                self.message_buffer[c] = b;
+                self.state = MidiState::ExpectAnyByte;
+                Ok(Some(0..c + 1))
+            }
+            // These status arms match in every state: a status byte arriving mid-SysEx aborts it
+            // and begins a new message, just as it replaces the running status of a channel-voice
+            // message.
             (_, ByteType::StatusByteOne(s)) => {
                 self.state = MidiState::ExpectOneDataByte(s);
-                return None;
+                Ok(None)
             }
             (_, ByteType::StatusByteTwo(s)) => {
                 self.state = MidiState::ExpectFirstDataByte(s);
-                return None;
+                Ok(None)
             }
             (_, ByteType::SysExStartByte(s)) => {
                 self.state = MidiState::ExpectSysExData(0);
                 self.message_buffer[0] = s;
-                return None;
+                Ok(None)
             }
             (_, ByteType::PassThroughByte(b)) => {
+                // Mid-SysEx, put the real-time byte in the first free slot after the bytes already
+                // buffered so they are not overwritten.
+                let free = match self.state {
+                    MidiState::ExpectSysExData(n) if n + 1 < N => Some(n + 1),
+                    MidiState::ExpectSysExFragment(c) => Some(c),
+                    _ => None,
+                };
+                if let Some(i) = free {
+                    self.message_buffer[i] = b;
+                    return Ok(Some(i..i + 1));
+                }
                 self.message_buffer[0] = b;
-                return Some(&self.message_buffer[0..1]);
+                Ok(Some(0..1))
+            }
+            (_, ByteType::UndefinedByte) => Ok(None),
+            // A data byte with no status to attach it to is malformed input.
+            (MidiState::ExpectAnyByte, ByteType::DataByte(_)) => {
+                Err(StreamError::UnexpectedDataByte)
             }
-            (_, ByteType::UndefinedByte) => None,
             _ => {
                 self.state = MidiState::ExpectAnyByte;
-                return None;
+                Ok(None)
             }
         }
     }
 
+    /// Advance by one byte and, on a message boundary, return the buffer range holding the
+    /// completed message — the borrow-free primitive the [`ReadMessages`] / [`MessageStream`]
+    /// adapters loop over. Malformed input is silently skipped (like [`push`](Self::push)), and in
+    /// [`SysExMode::Fragments`] nothing is ever returned, since partial fragments are not decodable
+    /// messages.
+    #[cfg(any(feature = "std", feature = "async"))]
+    fn next_message_range(&mut self, byte: u8) -> Option<core::ops::Range<usize>> {
+        if self.sysex_mode != SysExMode::SingleBuffer {
+            let _ = self.advance(byte);
+            return None;
+        }
+        self.advance(byte).ok().flatten()
+    }
+
+    /// Feed a single byte through the state machine like [`push`](Self::push), but decode a
+    /// completed message into the crate's [`MidiMessage`](crate::MidiMessage) instead of handing
+    /// back the raw bytes. Returns `Some` on a message boundary (including real-time bytes
+    /// extracted mid-message) and `None` while a message is still being assembled.
+    ///
+    /// Only meaningful under [`SysExMode::SingleBuffer`] (the default): message decoding needs a
+    /// whole message, but [`SysExMode::Fragments`] emits partial SysEx slices that
+    /// [`MidiMessage::try_from`](crate::MidiMessage) cannot decode. To avoid handing back spurious
+    /// decode errors, this method always returns `None` in [`SysExMode::Fragments`] (the byte is
+    /// still consumed to keep FSM state consistent); use the raw-byte [`push`](Self::push) /
+    /// [`try_push`](Self::try_push) API to reassemble fragments yourself.
+    pub fn push_message(&mut self, byte: u8) -> Option<Result<crate::MidiMessage<'_>, crate::FromBytesError>> {
+        if self.sysex_mode != SysExMode::SingleBuffer {
+            // Fragment mode never yields a complete, decodable message; consume the byte and bail
+            // out with a defined `None` rather than feeding a partial slice to `try_from`.
+            let _ = self.push(byte);
+            return None;
+        }
+        self.push(byte).map(crate::MidiMessage::try_from)
+    }
+
+    /// Consume this parser and drive it over `reader`, producing a blocking iterator of decoded
+    /// messages. State (running status, partial SysEx) is carried across reads; the iterator ends
+    /// at EOF. Gated behind the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn iter_read<R: std::io::Read>(self, reader: R) -> ReadMessages<'a, R, N> {
+        ReadMessages {
+            parser: self,
+            reader,
+            chunk: [0; 64],
+            filled: 0,
+            pos: 0,
+            done: false,
+        }
+    }
+
+    /// Consume this parser and wrap an [`AsyncRead`](futures::io::AsyncRead) source in a
+    /// [`MessageStream`], for use in tokio/embassy-style event loops. The [`MidiState`] machine and
+    /// buffer are carried across [`poll_next`](MessageStream::poll_next) calls, preserving
+    /// running-status and in-progress SysEx state. Gated behind the `async` feature.
+    #[cfg(feature = "async")]
+    pub fn stream<R: futures::io::AsyncRead + Unpin>(self, reader: R) -> MessageStream<'a, R, N> {
+        MessageStream {
+            parser: self,
+            reader,
+            chunk: [0; 64],
+            filled: 0,
+            pos: 0,
+            done: false,
+        }
+    }
+
     fn get_byte_type(byte: u8) -> ByteType {
         match byte & 0x80 {
             0x80 => match byte & 0xf0 {
@@ -141,6 +495,42 @@ mod test {
         );
     }
 
+    #[test]
+    fn note_on_message() {
+        let mut message_buffer: [u8; 3] = [0; 3];
+        let mut midi = StreamParser::new(&mut message_buffer);
+        let bytes: &[u8] = &[0x90, 66, 44];
+        assert_eq!(None, midi.push_message(bytes[0]).map(Result::unwrap));
+        assert_eq!(None, midi.push_message(bytes[1]).map(Result::unwrap));
+        assert_eq!(
+            midi.push_message(bytes[2]).unwrap().unwrap(),
+            crate::MidiMessage::NoteOn(
+                crate::Channel::Ch1,
+                crate::Note::from(66u8),
+                crate::U7::try_from(44).unwrap(),
+            ),
+            "Decodes into a NoteOn message",
+        );
+    }
+
+    #[test]
+    fn status_interrupts_sysex() {
+        let mut message_buffer: [u8; 3] = [0; 3];
+        let mut midi = StreamParser::new(&mut message_buffer);
+        // A status byte arriving mid-SysEx aborts the SysEx and starts a new message.
+        let all_bytes: &[u8] = &[0xf0, 10, 0x90, 66, 44];
+        let msg_bytes: &[u8] = &all_bytes[2..5];
+        assert_eq!(None, midi.push(all_bytes[0]));
+        assert_eq!(None, midi.push(all_bytes[1]));
+        assert_eq!(None, midi.push(all_bytes[2]));
+        assert_eq!(None, midi.push(all_bytes[3]));
+        assert_eq!(
+            midi.push(all_bytes[4]).unwrap(),
+            msg_bytes,
+            "Interrupting status is kept and its NoteOn is reconstructed"
+        );
+    }
+
     #[test]
     fn timing_clock() {
         let mut message_buffer: [u8; 3] = [0; 3];
@@ -169,6 +559,61 @@ mod test {
         );
     }
 
+    #[test]
+    fn sysex_overflow() {
+        let mut message_buffer: [u8; 3] = [0; 3];
+        let mut midi = StreamParser::new(&mut message_buffer);
+        let bytes: &[u8] = &[0xf0, 11, 22, 33, 44];
+        assert_eq!(Ok(None), midi.try_push(bytes[0]));
+        assert_eq!(Ok(None), midi.try_push(bytes[1]));
+        assert_eq!(Ok(None), midi.try_push(bytes[2]));
+        assert_eq!(
+            Err(StreamError::BufferOverflow),
+            midi.try_push(bytes[3]),
+            "Overflowing SysEx reports an error instead of panicking"
+        );
+        // The parser has resynced and a fresh status byte parses normally again.
+        assert_eq!(Ok(None), midi.try_push(0x90));
+    }
+
+    #[test]
+    fn unexpected_data_byte() {
+        let mut message_buffer: [u8; 3] = [0; 3];
+        let mut midi = StreamParser::new(&mut message_buffer);
+        assert_eq!(
+            Err(StreamError::UnexpectedDataByte),
+            midi.try_push(44),
+            "A data byte with no running status is rejected"
+        );
+    }
+
+    #[test]
+    fn sysex_fragments() {
+        let mut message_buffer: [u8; 4] = [0; 4];
+        let mut midi =
+            StreamParser::new(&mut message_buffer).with_sysex_mode(SysExMode::Fragments);
+        let bytes: &[u8] = &[0xf0, 1, 2, 3, 4, 5, 0xf7];
+        assert_eq!(None, midi.push(bytes[0]));
+        assert_eq!(None, midi.push(bytes[1]));
+        assert_eq!(None, midi.push(bytes[2]));
+        // Buffer fills: first fragment keeps the leading 0xf0 and signals more to come.
+        assert_eq!(
+            &[0xf0, 1, 2, 3][..],
+            midi.push(bytes[3]).unwrap(),
+            "First fragment carries the leading 0xf0"
+        );
+        assert!(midi.sysex_fragment_pending());
+        assert_eq!(None, midi.push(bytes[4]));
+        assert_eq!(None, midi.push(bytes[5]));
+        // Final fragment carries the trailing 0xf7 and clears the pending flag.
+        assert_eq!(
+            &[4, 5, 0xf7][..],
+            midi.push(bytes[6]).unwrap(),
+            "Final fragment carries the trailing 0xf7"
+        );
+        assert!(!midi.sysex_fragment_pending());
+    }
+
     #[test]
     fn real_time_messages() {
         let mut message_buffer: [u8; 3] = [0; 3];